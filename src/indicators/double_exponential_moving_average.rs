@@ -0,0 +1,142 @@
+use std::fmt;
+use {Close, Next, Reset};
+use errors::*;
+use indicators::RelativeMovingAverage;
+
+/// Double exponential moving average (DEMA)
+///
+/// Layers a second pass of exponential smoothing over the first to reduce the lag
+/// inherent to a plain moving average.
+///
+/// # Formula
+///
+/// DEMA(_length_) = 2 * _EMA1_ - _EMA2_
+///
+/// Where:
+///
+/// * _EMA1_ = EMA(_length_) of the input
+/// * _EMA2_ = EMA(_length_) of _EMA1_
+///
+/// # Parameters
+///
+/// * _length_ - number of periods (integer greater than 0)
+///
+/// # Example
+///
+///
+/// # Links
+///
+/// * [Double exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Double_exponential_moving_average)
+///
+#[derive(Debug, Clone)]
+pub struct DoubleExponentialMovingAverage {
+    length: u32,
+    ema1: RelativeMovingAverage,
+    ema2: RelativeMovingAverage,
+}
+
+impl DoubleExponentialMovingAverage {
+    pub fn new(length: u32) -> Result<Self> {
+        let indicator = Self {
+            length: length,
+            ema1: RelativeMovingAverage::new(length)?,
+            ema2: RelativeMovingAverage::new(length)?,
+        };
+        Ok(indicator)
+    }
+
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+impl Next<f64> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        2.0 * ema1 - ema2
+    }
+}
+
+impl<'a, T: Close> Next<&'a T> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DoubleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+    }
+}
+
+impl Default for DoubleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for DoubleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DEMA({})", self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_helper::*;
+
+    test_indicator!(DoubleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(DoubleExponentialMovingAverage::new(0).is_err());
+        assert!(DoubleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(dema.next(2.0), 2.0);
+        assert_eq!(dema.next(5.0), 3.3125);
+        assert_eq!(dema.next(1.0), 2.40625);
+
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(dema.next(&bar1), 2.0);
+        assert_eq!(dema.next(&bar2), 3.3125);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dema = DoubleExponentialMovingAverage::new(5).unwrap();
+
+        assert_eq!(dema.next(4.0), 4.0);
+        dema.next(10.0);
+        dema.next(15.0);
+        dema.next(20.0);
+        assert_ne!(dema.next(4.0), 4.0);
+
+        dema.reset();
+        assert_eq!(dema.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        DoubleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dema = DoubleExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", dema), "DEMA(7)");
+    }
+}
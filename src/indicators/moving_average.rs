@@ -0,0 +1,159 @@
+use std::fmt;
+use std::str::FromStr;
+use {Close, Next, Reset};
+use errors::*;
+use indicators::{
+    DoubleExponentialMovingAverage, RelativeMovingAverage, TripleExponentialMovingAverage,
+    WeightedMovingAverage,
+};
+
+/// Selects which smoothing algorithm a `MovingAverage` should wrap.
+///
+/// There is no separate `Ema` variant: `RelativeMovingAverage` (its own
+/// `Display` impl calls itself "EMA") is the only EMA-family smoother this
+/// crate provides, so `Rma` covers that ground too. `"ema"` still parses to
+/// `Rma` for callers used to that name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovingAverageKind {
+    Rma,
+    Wma,
+    Dema,
+    Tema,
+}
+
+impl FromStr for MovingAverageKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ema" | "rma" => Ok(MovingAverageKind::Rma),
+            "wma" => Ok(MovingAverageKind::Wma),
+            "dema" => Ok(MovingAverageKind::Dema),
+            "tema" => Ok(MovingAverageKind::Tema),
+            _ => Err(Error::from_kind(ErrorKind::InvalidParameter)),
+        }
+    }
+}
+
+/// A moving average parameterized by `MovingAverageKind` so composite indicators
+/// (a MACD, a smoothed RSI, ...) can accept a configurable smoothing method instead
+/// of hard-coding one.
+///
+/// # Example
+///
+///
+#[derive(Debug, Clone)]
+pub enum MovingAverage {
+    Rma(RelativeMovingAverage),
+    Wma(WeightedMovingAverage),
+    Dema(DoubleExponentialMovingAverage),
+    Tema(TripleExponentialMovingAverage),
+}
+
+impl MovingAverage {
+    pub fn new(kind: MovingAverageKind, length: u32) -> Result<Self> {
+        let indicator = match kind {
+            MovingAverageKind::Rma => MovingAverage::Rma(RelativeMovingAverage::new(length)?),
+            MovingAverageKind::Wma => MovingAverage::Wma(WeightedMovingAverage::new(length)?),
+            MovingAverageKind::Dema => {
+                MovingAverage::Dema(DoubleExponentialMovingAverage::new(length)?)
+            }
+            MovingAverageKind::Tema => {
+                MovingAverage::Tema(TripleExponentialMovingAverage::new(length)?)
+            }
+        };
+        Ok(indicator)
+    }
+}
+
+impl Next<f64> for MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        match *self {
+            MovingAverage::Rma(ref mut indicator) => indicator.next(input),
+            MovingAverage::Wma(ref mut indicator) => indicator.next(input),
+            MovingAverage::Dema(ref mut indicator) => indicator.next(input),
+            MovingAverage::Tema(ref mut indicator) => indicator.next(input),
+        }
+    }
+}
+
+impl<'a, T: Close> Next<&'a T> for MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for MovingAverage {
+    fn reset(&mut self) {
+        match *self {
+            MovingAverage::Rma(ref mut indicator) => indicator.reset(),
+            MovingAverage::Wma(ref mut indicator) => indicator.reset(),
+            MovingAverage::Dema(ref mut indicator) => indicator.reset(),
+            MovingAverage::Tema(ref mut indicator) => indicator.reset(),
+        }
+    }
+}
+
+impl fmt::Display for MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MovingAverage::Rma(ref indicator) => write!(f, "{}", indicator),
+            MovingAverage::Wma(ref indicator) => write!(f, "{}", indicator),
+            MovingAverage::Dema(ref indicator) => write!(f, "{}", indicator),
+            MovingAverage::Tema(ref indicator) => write!(f, "{}", indicator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_helper::*;
+
+    #[test]
+    fn test_kind_from_str() {
+        assert_eq!("EMA".parse::<MovingAverageKind>().unwrap(), MovingAverageKind::Rma);
+        assert_eq!("Rma".parse::<MovingAverageKind>().unwrap(), MovingAverageKind::Rma);
+        assert!("bogus".parse::<MovingAverageKind>().is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(MovingAverage::new(MovingAverageKind::Rma, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageKind::Rma, 3).is_ok());
+    }
+
+    #[test]
+    fn test_next_matches_wrapped_indicator() {
+        let mut ma = MovingAverage::new(MovingAverageKind::Rma, 3).unwrap();
+        let mut rma = RelativeMovingAverage::new(3).unwrap();
+
+        assert_eq!(ma.next(2.0), rma.next(2.0));
+        assert_eq!(ma.next(5.0), rma.next(5.0));
+
+        let bar = Bar::new().close(1);
+        assert_eq!(ma.next(&bar), rma.next(&bar));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ma = MovingAverage::new(MovingAverageKind::Rma, 5).unwrap();
+
+        assert_eq!(ma.next(4.0), 4.0);
+        ma.next(10.0);
+        assert_ne!(ma.next(4.0), 4.0);
+
+        ma.reset();
+        assert_eq!(ma.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let ma = MovingAverage::new(MovingAverageKind::Rma, 7).unwrap();
+        assert_eq!(format!("{}", ma), "EMA(7)");
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+use {Close, Next, Reset};
+use errors::*;
+use indicators::RelativeMovingAverage;
+
+/// Moving average convergence divergence (MACD)
+///
+/// Built on top of the same exponential-smoothing recurrence as `RelativeMovingAverage`:
+/// a fast and a slow smoothed average are subtracted to form the MACD line, and that
+/// line is itself smoothed to form the signal line.
+///
+/// # Formula
+///
+/// MACD(fast, slow, signal):
+///
+/// * _MACD_ = _EMA_(_fast_) - _EMA_(_slow_)
+/// * _Signal_ = _EMA_(_signal_) of _MACD_
+/// * _Histogram_ = _MACD_ - _Signal_
+///
+/// # Parameters
+///
+/// * _fast_length_ - number of periods for the fast average (integer greater than 0)
+/// * _slow_length_ - number of periods for the slow average (integer greater than 0)
+/// * _signal_length_ - number of periods for the signal average (integer greater than 0)
+///
+/// # Example
+///
+///
+/// # Links
+///
+/// * [Moving average convergence/divergence, Wikipedia](https://en.wikipedia.org/wiki/MACD)
+///
+#[derive(Debug, Clone)]
+pub struct MovingAverageConvergenceDivergence {
+    fast: RelativeMovingAverage,
+    slow: RelativeMovingAverage,
+    signal: RelativeMovingAverage,
+}
+
+/// Output of the `MovingAverageConvergenceDivergence` indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovingAverageConvergenceDivergenceOutput {
+    pub macd: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+
+impl MovingAverageConvergenceDivergence {
+    pub fn new(fast_length: u32, slow_length: u32, signal_length: u32) -> Result<Self> {
+        let indicator = Self {
+            fast: RelativeMovingAverage::new(fast_length)?,
+            slow: RelativeMovingAverage::new(slow_length)?,
+            signal: RelativeMovingAverage::new(signal_length)?,
+        };
+        Ok(indicator)
+    }
+}
+
+impl Next<f64> for MovingAverageConvergenceDivergence {
+    type Output = MovingAverageConvergenceDivergenceOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let macd = self.fast.next(input) - self.slow.next(input);
+        let signal = self.signal.next(macd);
+
+        MovingAverageConvergenceDivergenceOutput {
+            macd: macd,
+            signal: signal,
+            histogram: macd - signal,
+        }
+    }
+}
+
+impl<'a, T: Close> Next<&'a T> for MovingAverageConvergenceDivergence {
+    type Output = MovingAverageConvergenceDivergenceOutput;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for MovingAverageConvergenceDivergence {
+    fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.signal.reset();
+    }
+}
+
+impl Default for MovingAverageConvergenceDivergence {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl fmt::Display for MovingAverageConvergenceDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MACD({}, {}, {})",
+            self.fast.length(),
+            self.slow.length(),
+            self.signal.length()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_helper::*;
+
+    test_indicator!(MovingAverageConvergenceDivergence);
+
+    #[test]
+    fn test_new() {
+        assert!(MovingAverageConvergenceDivergence::new(0, 26, 9).is_err());
+        assert!(MovingAverageConvergenceDivergence::new(12, 0, 9).is_err());
+        assert!(MovingAverageConvergenceDivergence::new(12, 26, 0).is_err());
+        assert!(MovingAverageConvergenceDivergence::new(12, 26, 9).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap();
+
+        let out = macd.next(2.0);
+        assert_eq!(out.macd, 0.0);
+        assert_eq!(out.signal, 0.0);
+        assert_eq!(out.histogram, 0.0);
+
+        let out = macd.next(5.0);
+        assert_eq!(out.macd, 0.3214285714285712);
+        assert_eq!(out.signal, 0.06428571428571424);
+        assert_eq!(out.histogram, 0.25714285714285695);
+
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        let out1 = macd.next(&bar1);
+        let out2 = macd.next(&bar2);
+        assert_eq!(out1.macd, 0.0);
+        assert_eq!(out2.macd, 0.3214285714285712);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap();
+
+        macd.next(2.0);
+        macd.next(5.0);
+        macd.next(1.0);
+
+        macd.reset();
+        let out = macd.next(4.0);
+        assert_eq!(out.macd, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        MovingAverageConvergenceDivergence::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let macd = MovingAverageConvergenceDivergence::new(12, 26, 9).unwrap();
+        assert_eq!(format!("{}", macd), "MACD(12, 26, 9)");
+    }
+}
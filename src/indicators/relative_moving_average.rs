@@ -6,6 +6,20 @@ use errors::*;
 ///
 /// Exactly the same like EMA, except what it uses alpha = 1 / y
 ///
+/// By default, the first input seeds `current` directly (`is_new`), which biases
+/// early output heavily toward that single sample. Constructing with
+/// `new_bias_corrected` instead tracks `moment = (1-k)*moment + k*input` starting
+/// from zero and divides out the accumulated weight, `moment / (1 - (1-k)^t)`, so
+/// the warmup window converges to the true EMA without the flat-start artifact.
+/// At `t=1` the two modes agree exactly; they diverge afterwards.
+///
+/// It also tracks a `void_fraction`: how much of the current value is not yet backed
+/// by genuine samples. It starts at `1.0`, is multiplied by `(1 - k)` on every
+/// `next` and every `forget`, and falls toward `0` as real samples accumulate.
+/// `forget` decays this weight without feeding a new observation, for sliding or
+/// batch windows where some steps contribute no data; `peek` reports the current
+/// value only once enough of it is backed by real samples.
+///
 /// # Parameters
 ///
 /// * _length_ - number of periods (integer greater than 0)
@@ -22,16 +36,42 @@ pub struct RelativeMovingAverage {
     length: u32,
     k:  f64,
     current: f64,
-    is_new: bool
+    is_new: bool,
+    bias_corrected: bool,
+    moment: f64,
+    t: u32,
+    void_fraction: f64,
+    relevance_threshold: f64,
 }
 
 impl RelativeMovingAverage {
     pub fn new(length: u32) -> Result<Self> {
+        Self::new_with_mode(length, false)
+    }
+
+    /// Same as `new`, but seeds the recurrence from zero and divides out the
+    /// accumulated weight on each step instead of seeding `current` with the
+    /// first input. See the struct-level docs for the difference.
+    pub fn new_bias_corrected(length: u32) -> Result<Self> {
+        Self::new_with_mode(length, true)
+    }
+
+    fn new_with_mode(length: u32, bias_corrected: bool) -> Result<Self> {
         match length {
             0 => Err(Error::from_kind(ErrorKind::InvalidParameter)),
             _ => {
                 let k = 1f64 / (length as f64 + 1f64);
-                let indicator = Self { length: length, k: k, current: 0f64, is_new: true };
+                let indicator = Self {
+                    length: length,
+                    k: k,
+                    current: 0f64,
+                    is_new: true,
+                    bias_corrected: bias_corrected,
+                    moment: 0f64,
+                    t: 0,
+                    void_fraction: 1.0,
+                    relevance_threshold: 0.5,
+                };
                 Ok(indicator)
             }
         }
@@ -40,18 +80,51 @@ impl RelativeMovingAverage {
     pub fn length(&self) -> u32 {
         self.length
     }
+
+    /// Sets the `void_fraction` threshold above which `peek` reports no value yet.
+    pub fn with_relevance_threshold(mut self, relevance_threshold: f64) -> Self {
+        self.relevance_threshold = relevance_threshold;
+        self
+    }
+
+    /// Decays `void_fraction` without feeding a new observation, as if a step in
+    /// the window contributed no sample.
+    pub fn forget(&mut self) {
+        self.void_fraction *= 1.0 - self.k;
+    }
+
+    /// Returns the current value, unless `void_fraction` still exceeds
+    /// `relevance_threshold`, i.e. too few genuine samples back it yet.
+    pub fn peek(&self) -> Option<f64> {
+        if self.void_fraction > self.relevance_threshold {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
 }
 
 impl Next<f64> for RelativeMovingAverage {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        if self.is_new {
+        if self.bias_corrected {
+            self.t += 1;
+            self.moment = self.k * input + (1.0 - self.k) * self.moment;
+            self.current = if self.t == 1 {
+                // `1.0 - (1.0 - k).powi(1)` is a near-cancellation that loses
+                // precision relative to dividing by `k` directly.
+                self.moment / self.k
+            } else {
+                self.moment / (1.0 - (1.0 - self.k).powi(self.t as i32))
+            };
+        } else if self.is_new {
             self.is_new = false;
             self.current = input;
         } else {
             self.current = self.k * input + (1.0 - self.k) * self.current;
         }
+        self.void_fraction *= 1.0 - self.k;
         self.current
     }
 }
@@ -68,6 +141,9 @@ impl Reset for RelativeMovingAverage {
     fn reset(&mut self) {
         self.current = 0.0;
         self.is_new = true;
+        self.moment = 0.0;
+        self.t = 0;
+        self.void_fraction = 1.0;
     }
 }
 
@@ -127,6 +203,61 @@ mod tests {
         assert_eq!(ema.next(4.0), 4.0);
     }
 
+    #[test]
+    fn test_next_bias_corrected() {
+        let mut rma = RelativeMovingAverage::new_bias_corrected(3).unwrap();
+
+        // Agrees with the uncorrected recurrence on the very first sample.
+        assert_eq!(rma.next(2.0), 2.0);
+        // But diverges afterwards as the accumulated weight is divided out.
+        assert_eq!(rma.next(5.0), 3.7142857142857144);
+        assert_eq!(rma.next(1.0), 2.5405405405405403);
+    }
+
+    #[test]
+    fn test_reset_bias_corrected() {
+        let mut rma = RelativeMovingAverage::new_bias_corrected(5).unwrap();
+
+        rma.next(4.0);
+        rma.next(10.0);
+        rma.reset();
+
+        assert_eq!(rma.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_peek_before_enough_samples() {
+        let mut rma = RelativeMovingAverage::new(3).unwrap();
+
+        // void_fraction starts at 1.0, above the default 0.5 threshold.
+        assert_eq!(rma.peek(), None);
+        rma.next(2.0);
+        // void_fraction is now 0.75, still above the threshold.
+        assert_eq!(rma.peek(), None);
+        rma.next(5.0);
+        // void_fraction is now 0.5625, still above the threshold.
+        assert_eq!(rma.peek(), None);
+        rma.next(1.0);
+        // void_fraction is now 0.421875, below the threshold.
+        assert_eq!(rma.peek(), Some(2.3125));
+    }
+
+    #[test]
+    fn test_forget_decays_void_fraction_without_new_input() {
+        let mut rma = RelativeMovingAverage::new(3).unwrap().with_relevance_threshold(0.2);
+
+        rma.next(2.0);
+        // void_fraction is 0.75, above the threshold: one sample isn't enough yet.
+        assert_eq!(rma.peek(), None);
+
+        for _ in 0..5 {
+            rma.forget();
+        }
+        // void_fraction has decayed to 0.177978515625, below the threshold, while
+        // the value itself is unchanged since no new sample was fed.
+        assert_eq!(rma.peek(), Some(2.0));
+    }
+
     #[test]
     fn test_default() {
         RelativeMovingAverage::default();
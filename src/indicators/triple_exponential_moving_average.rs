@@ -0,0 +1,148 @@
+use std::fmt;
+use {Close, Next, Reset};
+use errors::*;
+use indicators::RelativeMovingAverage;
+
+/// Triple exponential moving average (TEMA)
+///
+/// Adds a third pass of exponential smoothing to the two passes that
+/// `DoubleExponentialMovingAverage` uses, to further reduce lag relative to a plain
+/// moving average.
+///
+/// # Formula
+///
+/// TEMA(_length_) = 3 * _EMA1_ - 3 * _EMA2_ + _EMA3_
+///
+/// Where:
+///
+/// * _EMA1_ = EMA(_length_) of the input
+/// * _EMA2_ = EMA(_length_) of _EMA1_
+/// * _EMA3_ = EMA(_length_) of _EMA2_
+///
+/// # Parameters
+///
+/// * _length_ - number of periods (integer greater than 0)
+///
+/// # Example
+///
+///
+/// # Links
+///
+/// * [Triple exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
+///
+#[derive(Debug, Clone)]
+pub struct TripleExponentialMovingAverage {
+    length: u32,
+    ema1: RelativeMovingAverage,
+    ema2: RelativeMovingAverage,
+    ema3: RelativeMovingAverage,
+}
+
+impl TripleExponentialMovingAverage {
+    pub fn new(length: u32) -> Result<Self> {
+        let indicator = Self {
+            length: length,
+            ema1: RelativeMovingAverage::new(length)?,
+            ema2: RelativeMovingAverage::new(length)?,
+            ema3: RelativeMovingAverage::new(length)?,
+        };
+        Ok(indicator)
+    }
+
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+impl Next<f64> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        let ema3 = self.ema3.next(ema2);
+        3.0 * ema1 - 3.0 * ema2 + ema3
+    }
+}
+
+impl<'a, T: Close> Next<&'a T> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for TripleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+}
+
+impl Default for TripleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for TripleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TEMA({})", self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_helper::*;
+
+    test_indicator!(TripleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(TripleExponentialMovingAverage::new(0).is_err());
+        assert!(TripleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(tema.next(2.0), 2.0);
+        assert_eq!(tema.next(5.0), 3.734375);
+        assert_eq!(tema.next(1.0), 2.37109375);
+
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(tema.next(&bar1), 2.0);
+        assert_eq!(tema.next(&bar2), 3.734375);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tema = TripleExponentialMovingAverage::new(5).unwrap();
+
+        assert_eq!(tema.next(4.0), 4.0);
+        tema.next(10.0);
+        tema.next(15.0);
+        tema.next(20.0);
+        assert_ne!(tema.next(4.0), 4.0);
+
+        tema.reset();
+        assert_eq!(tema.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        TripleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let tema = TripleExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", tema), "TEMA(7)");
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use {Close, Next, Reset};
+
+/// Running mean, variance and standard error (Welford's algorithm)
+///
+/// Tracks the sample mean and unbiased sample variance of every input seen so far
+/// in constant memory, without revisiting past samples. Pairs naturally with the
+/// smoothing indicators in this crate to build volatility bands such as Bollinger-style
+/// envelopes.
+///
+/// # Formula
+///
+/// On each `next(x)`, with running count _n_:
+///
+/// * _delta_ = _x_ - _mean_
+/// * _mean_ += _delta_ / _n_
+/// * _m2_ += _delta_ * (_x_ - _mean_)
+/// * _variance_ = _m2_ / (_n_ - 1) (`0` while _n_ < 2)
+/// * _error_ = sqrt(_variance_ / _n_)
+///
+/// # Links
+///
+/// * [Algorithms for calculating variance, Wikipedia](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm)
+///
+#[derive(Debug, Clone)]
+pub struct Variance {
+    n: u64,
+    mean: f64,
+    m2: f64,
+}
+
+/// Output of the `Variance` indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VarianceOutput {
+    pub mean: f64,
+    pub variance: f64,
+    pub error: f64,
+}
+
+impl Variance {
+    pub fn new() -> Self {
+        Self {
+            n: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+}
+
+impl Next<f64> for Variance {
+    type Output = VarianceOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.n += 1;
+        let delta = input - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (input - self.mean);
+
+        let variance = if self.n < 2 {
+            0.0
+        } else {
+            self.m2 / (self.n - 1) as f64
+        };
+        let error = (variance / self.n as f64).sqrt();
+
+        VarianceOutput {
+            mean: self.mean,
+            variance: variance,
+            error: error,
+        }
+    }
+}
+
+impl<'a, T: Close> Next<&'a T> for Variance {
+    type Output = VarianceOutput;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Variance {
+    fn reset(&mut self) {
+        self.n = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+}
+
+impl Default for Variance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Variance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VARIANCE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_helper::*;
+
+    test_indicator!(Variance);
+
+    #[test]
+    fn test_next() {
+        let mut var = Variance::new();
+
+        let out = var.next(2.0);
+        assert_eq!(out.mean, 2.0);
+        assert_eq!(out.variance, 0.0);
+        assert_eq!(out.error, 0.0);
+
+        let out = var.next(5.0);
+        assert_eq!(out.mean, 3.5);
+        assert_eq!(out.variance, 4.5);
+        assert_eq!(out.error, (4.5f64 / 2.0).sqrt());
+
+        let out = var.next(1.0);
+        assert_eq!(out.mean, 8.0 / 3.0);
+        assert_eq!(out.variance, 4.333333333333333);
+
+        let mut var = Variance::new();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(var.next(&bar1).mean, 2.0);
+        assert_eq!(var.next(&bar2).mean, 3.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut var = Variance::new();
+
+        var.next(2.0);
+        var.next(5.0);
+        var.reset();
+
+        let out = var.next(4.0);
+        assert_eq!(out.mean, 4.0);
+        assert_eq!(out.variance, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Variance::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let var = Variance::new();
+        assert_eq!(format!("{}", var), "VARIANCE");
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+use std::fmt;
+use {Close, Next, Reset};
+use errors::*;
+
+/// Weighted moving average (WMA)
+///
+/// Unlike the exponential recurrence in `RelativeMovingAverage`, WMA keeps the last
+/// `length` closes around explicitly and weights the most recent one the heaviest.
+///
+/// # Formula
+///
+/// WMA(_length_) = sum(_w_i_ * _x_i_) / (_length_ * (_length_ + 1) / 2)
+///
+/// Where _x_i_ is the i-th most recent input and _w_i_ = _length_ - _i_ + 1, so the
+/// latest input has weight _length_, the one before it _length_ - 1, and so on down to 1.
+/// Before `length` samples have been seen, the weighted average is taken over the
+/// samples available so far.
+///
+/// # Parameters
+///
+/// * _length_ - number of periods (integer greater than 0)
+///
+/// # Example
+///
+///
+/// # Links
+///
+/// * [Weighted moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average)
+///
+#[derive(Debug, Clone)]
+pub struct WeightedMovingAverage {
+    length: u32,
+    values: VecDeque<f64>,
+}
+
+impl WeightedMovingAverage {
+    pub fn new(length: u32) -> Result<Self> {
+        match length {
+            0 => Err(Error::from_kind(ErrorKind::InvalidParameter)),
+            _ => Ok(Self {
+                length: length,
+                values: VecDeque::with_capacity(length as usize),
+            }),
+        }
+    }
+
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+impl Next<f64> for WeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.values.len() == self.length as usize {
+            self.values.pop_front();
+        }
+        self.values.push_back(input);
+
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+        for (i, value) in self.values.iter().enumerate() {
+            let weight = (i + 1) as f64;
+            weighted_sum += weight * value;
+            weight_total += weight;
+        }
+
+        weighted_sum / weight_total
+    }
+}
+
+impl<'a, T: Close> Next<&'a T> for WeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for WeightedMovingAverage {
+    fn reset(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl Default for WeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for WeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WMA({})", self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_helper::*;
+
+    test_indicator!(WeightedMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(WeightedMovingAverage::new(0).is_err());
+        assert!(WeightedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+
+        assert_eq!(wma.next(2.0), 2.0);
+        assert_eq!(wma.next(5.0), (1.0 * 2.0 + 2.0 * 5.0) / 3.0);
+        assert_eq!(wma.next(1.0), (1.0 * 2.0 + 2.0 * 5.0 + 3.0 * 1.0) / 6.0);
+        assert_eq!(wma.next(8.0), (1.0 * 5.0 + 2.0 * 1.0 + 3.0 * 8.0) / 6.0);
+
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(wma.next(&bar1), 2.0);
+        assert_eq!(wma.next(&bar2), (1.0 * 2.0 + 2.0 * 5.0) / 3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+
+        wma.next(2.0);
+        wma.next(5.0);
+        wma.reset();
+
+        assert_eq!(wma.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WeightedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let wma = WeightedMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", wma), "WMA(7)");
+    }
+}